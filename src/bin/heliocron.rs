@@ -1,14 +1,29 @@
-use chrono::prelude::*;
-
-fn main() -> Result<(), String> {
-    println!("{}", heliocron::domain::DayPart::from_elevation_angle(
-        heliocron::calc::SolarCalculations::new(
-            Utc::now().into(),
-            heliocron::domain::Coordinates {
-                latitude: heliocron::domain::Latitude::new(49.8077)?,
-                longitude: heliocron::domain::Longitude::new(7.9647)?,
-            },
-        ).solar_elevation(),
-    ));
-    Ok(())
+use std::process;
+
+use heliocron::{calc::SolarCalculations, domain::Action, errors::HeliocronError, subcommands};
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = run().await {
+        eprintln!("Error: {e}");
+        process::exit(e.exit_code());
+    }
+}
+
+async fn run() -> Result<(), HeliocronError> {
+    let config = heliocron::cli::parse_config()?;
+    let solar_calculations = SolarCalculations::new(
+        config.date,
+        config.coordinates,
+        config.elevation,
+        config.precise,
+    );
+
+    match config.action {
+        Action::Report { json } => subcommands::display_report(solar_calculations, json),
+        Action::Poll { watch, json } => subcommands::poll(solar_calculations, watch, json),
+        Action::Wait { event, offset, run } => {
+            subcommands::wait(solar_calculations, event, offset, run).await
+        }
+    }
 }
@@ -0,0 +1,318 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Timelike, Utc};
+
+use crate::domain::{self, Coordinates, Elevation, Event, EventTime, VariableElevationEvent};
+
+/// The Sun's declination and the equation of time at a given moment, the two quantities every
+/// event-time calculation is built from.
+struct SolarPosition {
+    declination_degrees: f64,
+    equation_of_time_minutes: f64,
+}
+
+/// The maximum number of refinement passes `SolarCalculations::fixed_event_time` performs in
+/// `precise` mode.
+const MAX_REFINEMENT_ITERATIONS: u32 = 5;
+
+/// The convergence threshold, in minutes, for `precise` mode's iterative refinement (1 second).
+const REFINEMENT_CONVERGED_MINUTES: f64 = 1.0 / 60.0;
+
+/// Performs the solar position and event-time calculations for a specific moment and location.
+pub struct SolarCalculations {
+    date: DateTime<FixedOffset>,
+    coordinates: Coordinates,
+    elevation: Elevation,
+    precise: bool,
+}
+
+impl SolarCalculations {
+    pub fn new(
+        date: DateTime<FixedOffset>,
+        coordinates: Coordinates,
+        elevation: Elevation,
+        precise: bool,
+    ) -> Self {
+        Self {
+            date,
+            coordinates,
+            elevation,
+            precise,
+        }
+    }
+
+    /// The moment these calculations are pinned to.
+    pub fn date(&self) -> DateTime<FixedOffset> {
+        self.date
+    }
+
+    /// The observer's elevation these calculations correct the horizon dip for.
+    pub fn elevation(&self) -> Elevation {
+        self.elevation
+    }
+
+    /// Produce a copy of these calculations pinned to a different instant, e.g. for `poll --watch`.
+    pub fn refresh(&self, date: DateTime<FixedOffset>) -> Self {
+        Self {
+            date,
+            coordinates: self.coordinates.clone(),
+            elevation: self.elevation,
+            precise: self.precise,
+        }
+    }
+
+    fn julian_day_of_date(&self) -> f64 {
+        // Julian day for 12:00 UTC on the configured local calendar date; the single-pass
+        // calculation evaluates the solar position at this moment.
+        let epoch = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        2451545.0 + (self.date.naive_local().date() - epoch).num_days() as f64
+    }
+
+    /// Julian day for the moment `minutes_from_utc_midnight` minutes after UTC midnight on the
+    /// configured date, used by `precise` mode to re-evaluate the solar position at successive
+    /// event-time estimates rather than always at 12:00 UTC on the configured date.
+    fn julian_day_at(&self, minutes_from_utc_midnight: f64) -> f64 {
+        self.julian_day_of_date() - 0.5 + minutes_from_utc_midnight / 1440.0
+    }
+
+    fn midnight_utc(&self) -> DateTime<Utc> {
+        let date = self.date.naive_local().date();
+        Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
+            .unwrap()
+    }
+
+    fn minutes_to_datetime(&self, minutes_from_utc_midnight: f64) -> DateTime<FixedOffset> {
+        let instant = self.midnight_utc()
+            + Duration::milliseconds((minutes_from_utc_midnight * 60_000.0).round() as i64);
+        instant.with_timezone(self.date.offset())
+    }
+
+    /// The Sun's elevation angle above the horizon, in degrees, at `self.date`.
+    pub fn solar_elevation(&self) -> f64 {
+        let position = solar_position(self.julian_day_of_date());
+
+        let minutes_from_utc_midnight = self.date.time().num_seconds_from_midnight() as f64 / 60.0
+            - self.date.offset().local_minus_utc() as f64 / 60.0;
+        let true_solar_time = minutes_from_utc_midnight
+            + position.equation_of_time_minutes
+            + 4.0 * *self.coordinates.longitude;
+        let hour_angle_degrees = (true_solar_time / 4.0).rem_euclid(360.0) - 180.0;
+
+        solar_elevation_degrees(
+            *self.coordinates.latitude,
+            position.declination_degrees,
+            hour_angle_degrees,
+        )
+    }
+
+    fn solar_noon(&self) -> DateTime<FixedOffset> {
+        let position = solar_position(self.julian_day_of_date());
+        let minutes = 720.0 - 4.0 * *self.coordinates.longitude - position.equation_of_time_minutes;
+        self.minutes_to_datetime(minutes)
+    }
+
+    /// Solve for the event's time, in minutes from UTC midnight, given the Sun's position at the
+    /// moment being evaluated. Returns `None` if the event does not occur (polar day/night).
+    fn event_minutes_from_position(
+        &self,
+        position: &SolarPosition,
+        elevation_angle_degrees: f64,
+        ascending: bool,
+    ) -> Option<f64> {
+        let hour_angle = hour_angle_degrees(
+            *self.coordinates.latitude,
+            position.declination_degrees,
+            elevation_angle_degrees,
+        )?;
+
+        let solar_noon_minutes =
+            720.0 - 4.0 * *self.coordinates.longitude - position.equation_of_time_minutes;
+        let offset_minutes = 4.0 * hour_angle;
+        Some(if ascending {
+            solar_noon_minutes - offset_minutes
+        } else {
+            solar_noon_minutes + offset_minutes
+        })
+    }
+
+    /// Re-evaluate the solar position at each successive estimate of the event time and re-solve
+    /// for it, repeating until consecutive estimates differ by less than a second or
+    /// `MAX_REFINEMENT_ITERATIONS` is reached. This corrects the seconds-to-minutes of error the
+    /// single-pass, noon-evaluated position can introduce near the poles or for events far from
+    /// midday.
+    fn refine_event_minutes(
+        &self,
+        mut minutes: f64,
+        elevation_angle_degrees: f64,
+        ascending: bool,
+    ) -> Option<f64> {
+        for _ in 0..MAX_REFINEMENT_ITERATIONS {
+            let position = solar_position(self.julian_day_at(minutes));
+            let next_minutes =
+                self.event_minutes_from_position(&position, elevation_angle_degrees, ascending)?;
+
+            if (next_minutes - minutes).abs() < REFINEMENT_CONVERGED_MINUTES {
+                return Some(next_minutes);
+            }
+            minutes = next_minutes;
+        }
+
+        Some(minutes)
+    }
+
+    fn fixed_event_time(
+        &self,
+        fixed: &domain::FixedElevationEvent,
+    ) -> Option<DateTime<FixedOffset>> {
+        let elevation_angle_degrees = -*fixed.degrees_below_horizon;
+        let ascending = matches!(fixed.solar_direction, domain::Direction::Ascending);
+
+        let position = solar_position(self.julian_day_of_date());
+        let minutes = self.event_minutes_from_position(&position, elevation_angle_degrees, ascending)?;
+
+        let minutes = if self.precise {
+            self.refine_event_minutes(minutes, elevation_angle_degrees, ascending)?
+        } else {
+            minutes
+        };
+
+        Some(self.minutes_to_datetime(minutes))
+    }
+
+    /// Compute the time at which `event` occurs.
+    pub fn event_time(&self, event: Event) -> EventTime {
+        match event {
+            Event::Variable(VariableElevationEvent::SolarNoon) => {
+                EventTime::new(Some(self.solar_noon()))
+            }
+            Event::Fixed(fixed) => EventTime::new(self.fixed_event_time(&fixed)),
+        }
+    }
+}
+
+fn solar_position(julian_day: f64) -> SolarPosition {
+    // Standard NOAA solar position algorithm, evaluated in Julian centuries since J2000.0.
+    let t = (julian_day - 2451545.0) / 36525.0;
+
+    let l0 = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let m = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let m_rad = m.to_radians();
+    let e = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+    let center = (1.914602 - t * (0.004817 + 0.000014 * t)) * m_rad.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m_rad).sin()
+        + 0.000289 * (3.0 * m_rad).sin();
+
+    let true_longitude = l0 + center;
+    let apparent_longitude =
+        true_longitude - 0.00569 - 0.00478 * (125.04 - 1934.136 * t).to_radians().sin();
+
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity_correction =
+        mean_obliquity + 0.00256 * (125.04 - 1934.136 * t).to_radians().cos();
+
+    let declination_degrees = (obliquity_correction.to_radians().sin()
+        * apparent_longitude.to_radians().sin())
+    .asin()
+    .to_degrees();
+
+    let y = (obliquity_correction.to_radians() / 2.0).tan().powi(2);
+    let equation_of_time_minutes = 4.0
+        * (y * (2.0 * l0.to_radians()).sin() - 2.0 * e * m_rad.sin()
+            + 4.0 * e * y * m_rad.sin() * (2.0 * l0.to_radians()).cos()
+            - 0.5 * y * y * (4.0 * l0.to_radians()).sin()
+            - 1.25 * e * e * (2.0 * m_rad).sin())
+        .to_degrees();
+
+    SolarPosition {
+        declination_degrees,
+        equation_of_time_minutes,
+    }
+}
+
+fn solar_elevation_degrees(
+    latitude_degrees: f64,
+    declination_degrees: f64,
+    hour_angle_degrees: f64,
+) -> f64 {
+    let lat = latitude_degrees.to_radians();
+    let dec = declination_degrees.to_radians();
+    let ha = hour_angle_degrees.to_radians();
+
+    (lat.sin() * dec.sin() + lat.cos() * dec.cos() * ha.cos())
+        .asin()
+        .to_degrees()
+}
+
+/// Solve the hour angle, in degrees, at which the Sun reaches `elevation_angle_degrees`, or
+/// `None` if it never does that day (the polar day/night case).
+fn hour_angle_degrees(
+    latitude_degrees: f64,
+    declination_degrees: f64,
+    elevation_angle_degrees: f64,
+) -> Option<f64> {
+    let lat = latitude_degrees.to_radians();
+    let dec = declination_degrees.to_radians();
+    let h0 = elevation_angle_degrees.to_radians();
+
+    let cos_hour_angle = (h0.sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos());
+
+    if (-1.0..=1.0).contains(&cos_hour_angle) {
+        Some(cos_hour_angle.acos().to_degrees())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::{Coordinates, Latitude, Longitude};
+
+    fn calculations(precise: bool) -> SolarCalculations {
+        let date = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2026, 6, 21, 12, 0, 0)
+            .unwrap();
+        let coordinates = Coordinates::new(
+            Latitude::new(51.4769).unwrap(),
+            Longitude::new(-0.0005).unwrap(),
+        );
+        SolarCalculations::new(date, coordinates, Elevation::new(0.0), precise)
+    }
+
+    #[test]
+    fn precise_mode_refines_towards_the_same_answer_as_a_single_pass() {
+        let single_pass = calculations(false);
+        let precise = calculations(true);
+
+        let sunrise = domain::Event::from_event_name(
+            domain::EventName::Sunrise,
+            single_pass.elevation(),
+        );
+        let single_pass_time = single_pass.event_time(sunrise).0.unwrap();
+
+        let sunrise = domain::Event::from_event_name(domain::EventName::Sunrise, precise.elevation());
+        let precise_time = precise.event_time(sunrise).0.unwrap();
+
+        // Precise mode corrects the single pass's error, so the two estimates should be close
+        // but not necessarily identical.
+        assert!((precise_time - single_pass_time).num_seconds().abs() < 60);
+    }
+
+    #[test]
+    fn fixed_event_time_is_none_during_the_polar_day() {
+        let date = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2026, 6, 21, 12, 0, 0)
+            .unwrap();
+        let coordinates = Coordinates::new(
+            Latitude::new(78.2232).unwrap(),
+            Longitude::new(15.6267).unwrap(),
+        );
+        let calculations = SolarCalculations::new(date, coordinates, Elevation::new(0.0), false);
+
+        let sunset =
+            domain::Event::from_event_name(domain::EventName::Sunset, calculations.elevation());
+        assert!(calculations.event_time(sunset).0.is_none());
+    }
+}
@@ -1,6 +1,7 @@
-use std::{fs, path::PathBuf, result};
+use std::{fmt, fs, path::PathBuf, result};
 
-use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, TimeZone};
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, Offset, TimeZone};
+use chrono_tz::Tz;
 use clap::{Parser, Subcommand};
 use serde::Deserialize;
 
@@ -17,13 +18,14 @@ struct Cli {
         short = 'd',
         long = "date",
         value_parser=parse_date,
-        default_value_t=Local::today().naive_local()
+        default_value_t=Local::now().date_naive()
     )]
     date: NaiveDate,
 
-    /// Set the time zone. If specified, it should be in the format '[+/-]HH:MM', otherwise it defaults to the current local time zone
-    #[clap(short = 't', long = "time-zone", allow_hyphen_values = true, value_parser=parse_tz, default_value_t=*Local::today().offset())]
-    time_zone: FixedOffset,
+    /// Set the time zone. If specified, it should either be a fixed offset in the format '[+/-]HH:MM', or an IANA time
+    /// zone name such as 'Europe/Berlin'. Defaults to the current local time zone
+    #[clap(short = 't', long = "time-zone", allow_hyphen_values = true, value_parser=parse_tz, default_value_t=Zone::Fixed(*Local::now().offset()))]
+    time_zone: Zone,
 
     /// Set the latitude in decimal degrees. Positive values to the north; negative values to the south. Defaults to '51.4769' if not
     /// otherwise specified here or in ~/.config/heliocron.toml.
@@ -35,6 +37,18 @@ struct Cli {
     #[clap(short = 'o', long = "longitude", requires = "latitude", allow_hyphen_values = true, value_parser = domain::Longitude::parse)]
     longitude: Option<domain::Longitude>,
 
+    /// Set the observer's elevation above sea level in meters. This widens the horizon dip used for sunrise/sunset and the
+    /// twilight events (but not solar noon) to account for the higher horizon visible from an elevated position. Defaults to
+    /// '0.0'; negative or zero values have no effect
+    #[clap(short = 'e', long = "elevation", allow_hyphen_values = true, value_parser = domain::Elevation::parse, default_value_t = domain::Elevation::new(0.0))]
+    elevation: domain::Elevation,
+
+    /// Iteratively refine fixed-elevation event times (sunrise/sunset, twilights) by re-evaluating the Sun's position at each
+    /// successive estimate instead of once at 12:00 UTC on the configured date. More accurate near the poles or for events far from midday, at the
+    /// cost of a handful of extra calculations per event
+    #[clap(long = "precise")]
+    precise: bool,
+
     #[clap(subcommand)]
     subcommand: Command,
 }
@@ -58,6 +72,26 @@ pub enum Command {
         #[clap(long = "json")]
         json: bool,
     },
+
+    /// Wait until a solar event occurs, then exit or run a command
+    Wait {
+        /// The event to wait for, e.g. 'sunrise', 'civil_dusk', 'solar_noon', 'custom_am'
+        #[clap(value_parser = domain::RawEventName::parse)]
+        event: domain::RawEventName,
+
+        /// The elevation angle in degrees above/below the horizon. Required if, and only if, `event` is 'custom_am' or 'custom_pm'
+        #[clap(long = "altitude", allow_hyphen_values = true, value_parser = domain::Altitude::parse)]
+        altitude: Option<domain::Altitude>,
+
+        /// Offset the wait by a signed duration in the format '[+/-]HH:MM:SS'
+        #[clap(long = "offset", allow_hyphen_values = true, value_parser = parse_offset, default_value = "00:00:00")]
+        offset: chrono::Duration,
+
+        /// Run this shell command once the event (plus any offset) is reached. If not present, heliocron simply exits once the
+        /// event is reached, e.g. for chaining with '&&'
+        #[clap(long = "run")]
+        run: Option<String>,
+    },
 }
 
 fn parse_date(date: &str) -> Result<NaiveDate, String> {
@@ -65,15 +99,84 @@ fn parse_date(date: &str) -> Result<NaiveDate, String> {
         .map_err(|_| format!("Invalid date - must be in the format 'yyyy-mm-dd'. Found '{date}'"))
 }
 
-fn parse_tz(tz: &str) -> Result<chrono::FixedOffset, String> {
-    // Use chrono's own parsing function to validate the provided time zone.
-    let date = chrono::DateTime::parse_from_str(&format!("2022-01-01T00:00:00{}", tz), "%FT%T%:z")
-        .map_err(|_| {
-            format!(
-                "Invalid time zone - expected the format '[+|-]HH:MM' between '-23:59' and '+23:59'. Found '{tz}'"
-            )
-        })?;
-    Ok(*date.offset())
+/// A user-supplied time zone, either a fixed UTC offset or a named IANA zone.
+///
+/// Unlike a `FixedOffset`, a `Named` zone's offset isn't known until it's resolved against a
+/// specific date, since it may observe daylight saving time.
+#[derive(Clone, Copy)]
+pub enum Zone {
+    Fixed(FixedOffset),
+    Named(Tz),
+}
+
+impl Zone {
+    /// Resolve this time zone to the `FixedOffset` valid at local noon on `date`.
+    fn offset_at(&self, date: NaiveDate) -> Result<FixedOffset, String> {
+        match self {
+            Self::Fixed(offset) => Ok(*offset),
+            Self::Named(tz) => {
+                let noon = date.and_hms_opt(12, 0, 0).unwrap();
+                match tz.offset_from_local_datetime(&noon) {
+                    chrono::LocalResult::Single(offset) => Ok(offset.fix()),
+                    chrono::LocalResult::Ambiguous(offset, _) => Ok(offset.fix()),
+                    chrono::LocalResult::None => Err(format!(
+                        "'{date}' falls in a daylight saving time gap in time zone '{tz}' - local noon does not exist on that date"
+                    )),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(offset) => write!(f, "{offset}"),
+            Self::Named(tz) => write!(f, "{tz}"),
+        }
+    }
+}
+
+fn parse_tz(tz: &str) -> Result<Zone, String> {
+    // Fixed offsets are tried first so the existing '[+|-]HH:MM' syntax keeps working.
+    if let Ok(date) =
+        chrono::DateTime::parse_from_str(&format!("2022-01-01T00:00:00{}", tz), "%FT%T%:z")
+    {
+        return Ok(Zone::Fixed(*date.offset()));
+    }
+
+    tz.parse::<Tz>().map(Zone::Named).map_err(|_| {
+        format!(
+            "Invalid time zone - expected a fixed offset in the format '[+|-]HH:MM', or an IANA time zone name such as 'Europe/Berlin'. Found '{tz}'"
+        )
+    })
+}
+
+fn parse_offset(offset: &str) -> Result<chrono::Duration, String> {
+    let invalid = || {
+        format!("Invalid offset - expected the format '[+|-]HH:MM:SS'. Found '{offset}'")
+    };
+
+    let (negative, unsigned) = match offset.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, offset.strip_prefix('+').unwrap_or(offset)),
+    };
+
+    let mut parts = unsigned.splitn(3, ':');
+    let (hours, minutes, seconds) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(hours), Some(minutes), Some(seconds)) => (
+            hours.parse::<i64>().map_err(|_| invalid())?,
+            minutes.parse::<i64>().map_err(|_| invalid())?,
+            seconds.parse::<i64>().map_err(|_| invalid())?,
+        ),
+        _ => return Err(invalid()),
+    };
+
+    let duration = chrono::Duration::hours(hours)
+        + chrono::Duration::minutes(minutes)
+        + chrono::Duration::seconds(seconds);
+
+    Ok(if negative { -duration } else { duration })
 }
 
 #[derive(Debug, Deserialize)]
@@ -86,6 +189,8 @@ struct RawFileConfig {
 pub struct Config {
     pub coordinates: domain::Coordinates,
     pub date: DateTime<FixedOffset>,
+    pub elevation: domain::Elevation,
+    pub precise: bool,
     pub action: domain::Action,
 }
 
@@ -129,31 +234,53 @@ pub fn parse_config() -> Result<Config, HeliocronError> {
             let now = Local::now();
             now.with_timezone(now.offset())
         }
-        _ => cli_args
-            .time_zone
-            .ymd(
-                cli_args.date.year(),
-                cli_args.date.month(),
-                cli_args.date.day(),
-            )
-            .and_hms(12, 0, 0),
+        _ => {
+            let offset = cli_args
+                .time_zone
+                .offset_at(cli_args.date)
+                .map_err(HeliocronError::InvalidInput)?;
+            offset
+                .with_ymd_and_hms(
+                    cli_args.date.year(),
+                    cli_args.date.month(),
+                    cli_args.date.day(),
+                    12,
+                    0,
+                    0,
+                )
+                .unwrap()
+        }
     };
 
     let action = match cli_args.subcommand {
         Command::Report { json } => domain::Action::Report { json },
         Command::Poll { watch, json } => domain::Action::Poll { watch, json },
+        Command::Wait {
+            event,
+            altitude,
+            offset,
+            run,
+        } => {
+            let event = event
+                .into_event_name(altitude)
+                .map_err(HeliocronError::InvalidInput)?;
+            domain::Action::Wait { event, offset, run }
+        }
     };
 
     Ok(Config {
         coordinates,
         date,
+        elevation: cli_args.elevation,
+        precise: cli_args.precise,
         action,
     })
 }
 
 fn parse_local_config(path: &PathBuf) -> Result<domain::Coordinates, String> {
-    let config_file = fs::read(path).map_err(|_| "Failed to read config file path".to_string())?;
-    let toml_config = toml::from_slice::<RawFileConfig>(&config_file).map_err(|e| e.to_string())?;
+    let config_file =
+        fs::read_to_string(path).map_err(|_| "Failed to read config file path".to_string())?;
+    let toml_config = toml::from_str::<RawFileConfig>(&config_file).map_err(|e| e.to_string())?;
 
     let (lat, lon) = match (toml_config.latitude, toml_config.longitude) {
         (Some(lat), Some(lon)) => Ok((lat, lon)),
@@ -167,3 +294,28 @@ fn parse_local_config(path: &PathBuf) -> Result<domain::Coordinates, String> {
 
     Ok(domain::Coordinates::new(lat, lon))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_at_resolves_the_post_transition_offset_on_a_spring_forward_date() {
+        // Germany springs forward from CET (+01:00) to CEST (+02:00) at 02:00 local on this date,
+        // so local noon resolves unambiguously to the post-transition offset.
+        let date = NaiveDate::from_ymd_opt(2026, 3, 29).unwrap();
+        let zone = parse_tz("Europe/Berlin").unwrap();
+
+        let offset = zone.offset_at(date).unwrap();
+        assert_eq!(offset, FixedOffset::east_opt(2 * 3600).unwrap());
+    }
+
+    #[test]
+    fn offset_at_resolves_the_pre_transition_offset_the_day_before() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 28).unwrap();
+        let zone = parse_tz("Europe/Berlin").unwrap();
+
+        let offset = zone.offset_at(date).unwrap();
+        assert_eq!(offset, FixedOffset::east_opt(3600).unwrap());
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 use std::ops::RangeInclusive;
 
-use chrono::{DateTime, FixedOffset, NaiveTime};
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime};
 
 /// An enumeration of the different parts of the day. Not all of them necessarily occur during a
 /// given 24-hour period.
@@ -54,6 +54,11 @@ pub enum Action {
         watch: bool,
         json: bool,
     },
+    Wait {
+        event: EventName,
+        offset: Duration,
+        run: Option<String>,
+    },
 }
 
 /// A newtype representing an optional datetime.
@@ -128,6 +133,44 @@ impl std::ops::Deref for Altitude {
     }
 }
 
+/// An observer's elevation above sea level, in meters.
+///
+/// This widens the horizon depression angle used by fixed-elevation events (sunrise/sunset and
+/// the twilights), since an elevated observer sees the Sun rise earlier and set later than one
+/// at sea level. It has no effect on solar noon, which is elevation-independent.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Elevation(f64);
+
+impl Elevation {
+    pub fn new(meters: f64) -> Self {
+        Self(meters)
+    }
+
+    pub fn parse(meters: &str) -> Result<Self, String> {
+        meters
+            .parse()
+            .map(Self::new)
+            .map_err(|_| format!("Expected a number of meters. Found '{meters}'"))
+    }
+
+    /// The increase in the horizon depression angle, in degrees, caused by being elevated above
+    /// sea level, using the standard dip approximation: 1.76 arc-minutes * sqrt(height). Negative
+    /// or zero elevations contribute no dip.
+    pub fn dip_degrees(&self) -> f64 {
+        if self.0 <= 0.0 {
+            0.0
+        } else {
+            (1.76 * self.0.sqrt()) / 60.0
+        }
+    }
+}
+
+impl fmt::Display for Elevation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// A list of plain event names supported by the command line interface.
 #[derive(Clone)]
 pub enum RawEventName {
@@ -144,6 +187,51 @@ pub enum RawEventName {
     SolarNoon,
 }
 
+impl RawEventName {
+    /// Parse a CLI argument such as `"sunrise"` or `"custom_am"` into a `RawEventName`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "sunrise" => Ok(Self::Sunrise),
+            "sunset" => Ok(Self::Sunset),
+            "civil_dawn" => Ok(Self::CivilDawn),
+            "civil_dusk" => Ok(Self::CivilDusk),
+            "nautical_dawn" => Ok(Self::NauticalDawn),
+            "nautical_dusk" => Ok(Self::NauticalDusk),
+            "astronomical_dawn" => Ok(Self::AstronomicalDawn),
+            "astronomical_dusk" => Ok(Self::AstronomicalDusk),
+            "custom_am" => Ok(Self::CustomAM),
+            "custom_pm" => Ok(Self::CustomPM),
+            "solar_noon" => Ok(Self::SolarNoon),
+            _ => Err(format!(
+                "Invalid event - expected one of 'sunrise', 'sunset', 'civil_dawn', 'civil_dusk', \
+                 'nautical_dawn', 'nautical_dusk', 'astronomical_dawn', 'astronomical_dusk', \
+                 'custom_am', 'custom_pm', 'solar_noon'. Found '{value}'"
+            )),
+        }
+    }
+
+    /// Combine with the altitude required by `custom_am`/`custom_pm` into a full `EventName`.
+    pub fn into_event_name(self, altitude: Option<Altitude>) -> Result<EventName, String> {
+        match self {
+            Self::Sunrise => Ok(EventName::Sunrise),
+            Self::Sunset => Ok(EventName::Sunset),
+            Self::CivilDawn => Ok(EventName::CivilDawn),
+            Self::CivilDusk => Ok(EventName::CivilDusk),
+            Self::NauticalDawn => Ok(EventName::NauticalDawn),
+            Self::NauticalDusk => Ok(EventName::NauticalDusk),
+            Self::AstronomicalDawn => Ok(EventName::AstronomicalDawn),
+            Self::AstronomicalDusk => Ok(EventName::AstronomicalDusk),
+            Self::SolarNoon => Ok(EventName::SolarNoon),
+            Self::CustomAM => altitude
+                .map(EventName::CustomAM)
+                .ok_or_else(|| "event 'custom_am' requires '--altitude'".to_string()),
+            Self::CustomPM => altitude
+                .map(EventName::CustomPM)
+                .ok_or_else(|| "event 'custom_pm' requires '--altitude'".to_string()),
+        }
+    }
+}
+
 /// An enumeration of possible event names, with required data attached.
 ///
 /// For example, CustomAM/PM here include the custom altitude, in contrast to
@@ -204,41 +292,33 @@ pub enum Event {
 }
 
 impl Event {
-    pub fn from_event_name(event: EventName) -> Self {
+    /// Build the `Event` corresponding to `event`, widening the horizon depression angle of
+    /// fixed-elevation events by `elevation`'s dip correction. Solar noon ignores `elevation`,
+    /// since it isn't a horizon-relative event.
+    pub fn from_event_name(event: EventName, elevation: Elevation) -> Self {
         // We can just use `.into()` (a method which can panic) for these float conversions because we can manually
-        // verify that all of them are valid altitudes.
+        // verify that all of them are valid altitudes - except for the elevation dip, which can push an otherwise
+        // valid altitude above 90 degrees. Clamp it instead of panicking: an event whose (possibly custom) altitude
+        // plus dip exceeds 90 degrees simply never occurs, which is exactly what a 90-degree threshold reports.
+        let dip = elevation.dip_degrees();
+        let fixed = |degrees_below_horizon: f64, solar_direction: Direction| {
+            Self::Fixed(FixedElevationEvent::new(
+                (degrees_below_horizon + dip).min(90.0).into(),
+                solar_direction,
+            ))
+        };
+
         match event {
-            EventName::Sunrise => {
-                Self::Fixed(FixedElevationEvent::new(0.833.into(), Direction::Ascending))
-            }
-            EventName::Sunset => Self::Fixed(FixedElevationEvent::new(
-                0.833.into(),
-                Direction::Descending,
-            )),
-            EventName::CivilDawn => {
-                Self::Fixed(FixedElevationEvent::new(6.0.into(), Direction::Ascending))
-            }
-            EventName::CivilDusk => {
-                Self::Fixed(FixedElevationEvent::new(6.0.into(), Direction::Descending))
-            }
-            EventName::NauticalDawn => {
-                Self::Fixed(FixedElevationEvent::new(12.0.into(), Direction::Ascending))
-            }
-            EventName::NauticalDusk => {
-                Self::Fixed(FixedElevationEvent::new(12.0.into(), Direction::Descending))
-            }
-            EventName::AstronomicalDawn => {
-                Self::Fixed(FixedElevationEvent::new(18.0.into(), Direction::Ascending))
-            }
-            EventName::AstronomicalDusk => {
-                Self::Fixed(FixedElevationEvent::new(18.0.into(), Direction::Descending))
-            }
-            EventName::CustomAM(alt) => {
-                Self::Fixed(FixedElevationEvent::new(alt, Direction::Ascending))
-            }
-            EventName::CustomPM(alt) => {
-                Self::Fixed(FixedElevationEvent::new(alt, Direction::Descending))
-            }
+            EventName::Sunrise => fixed(0.833, Direction::Ascending),
+            EventName::Sunset => fixed(0.833, Direction::Descending),
+            EventName::CivilDawn => fixed(6.0, Direction::Ascending),
+            EventName::CivilDusk => fixed(6.0, Direction::Descending),
+            EventName::NauticalDawn => fixed(12.0, Direction::Ascending),
+            EventName::NauticalDusk => fixed(12.0, Direction::Descending),
+            EventName::AstronomicalDawn => fixed(18.0, Direction::Ascending),
+            EventName::AstronomicalDusk => fixed(18.0, Direction::Descending),
+            EventName::CustomAM(alt) => fixed(*alt, Direction::Ascending),
+            EventName::CustomPM(alt) => fixed(*alt, Direction::Descending),
             EventName::SolarNoon => Self::Variable(VariableElevationEvent::SolarNoon),
         }
     }
@@ -344,3 +424,31 @@ impl Coordinates {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elevation_dip_degrees_is_zero_at_or_below_sea_level() {
+        assert_eq!(Elevation::new(0.0).dip_degrees(), 0.0);
+        assert_eq!(Elevation::new(-100.0).dip_degrees(), 0.0);
+    }
+
+    #[test]
+    fn elevation_dip_degrees_widens_with_height() {
+        // 1.76 arc-minutes * sqrt(100) / 60 = 0.293(3) degrees.
+        assert!((Elevation::new(100.0).dip_degrees() - 0.29333).abs() < 1e-4);
+    }
+
+    #[test]
+    fn event_from_event_name_clamps_the_dip_adjusted_angle_instead_of_panicking() {
+        // A large enough elevation's dip, or a custom altitude already near the 90-degree limit
+        // plus a smaller dip, can push the widened angle past the 90-degree limit `Altitude` allows.
+        let huge_elevation = Elevation::new(600_000_000.0);
+        Event::from_event_name(EventName::Sunrise, huge_elevation);
+
+        let near_limit = Altitude::new(89.95).unwrap();
+        Event::from_event_name(EventName::CustomAM(near_limit), Elevation::new(12.0));
+    }
+}
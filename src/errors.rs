@@ -0,0 +1,46 @@
+use std::{fmt, io};
+
+/// The crate's unified error type.
+#[derive(Debug)]
+pub enum HeliocronError {
+    /// The user passed something that doesn't parse, or that doesn't make sense given the rest
+    /// of the configuration (e.g. an event/argument combination that's individually valid but
+    /// jointly nonsensical).
+    InvalidInput(String),
+    /// Something went wrong talking to the filesystem or a child process.
+    Io(String),
+    /// The configured date and location permit no finite solution for the calculation requested.
+    Calculation(String),
+}
+
+impl HeliocronError {
+    /// The process exit code this error should produce.
+    ///
+    /// `Calculation` gets a distinct code so that `wait`, run from cron or a systemd timer, can
+    /// be told apart from other failures when the requested event never occurs that day (e.g.
+    /// polar day/night) instead of blocking forever.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Calculation(_) => 2,
+            Self::InvalidInput(_) | Self::Io(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for HeliocronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInput(msg) => write!(f, "invalid input: {msg}"),
+            Self::Io(msg) => write!(f, "I/O error: {msg}"),
+            Self::Calculation(msg) => write!(f, "calculation error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for HeliocronError {}
+
+impl From<io::Error> for HeliocronError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err.to_string())
+    }
+}
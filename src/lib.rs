@@ -0,0 +1,7 @@
+pub mod calc;
+pub mod cli;
+pub mod domain;
+pub mod errors;
+pub mod report;
+pub mod subcommands;
+pub mod utils;
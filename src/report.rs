@@ -0,0 +1,213 @@
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+
+use crate::{
+    calc::SolarCalculations,
+    domain::{DayPart, Event, EventName},
+};
+
+/// A computed event time, or the reason it doesn't occur at all on the calculated date.
+///
+/// Distinguishing these cases (rather than collapsing both into a single "never" value) lets a
+/// report from somewhere like Tromsø in winter say whether the Sun stayed below the horizon all
+/// day or above it, instead of leaving the reader to guess.
+enum ReportedEvent {
+    Time(DateTime<FixedOffset>),
+    PolarDay,
+    PolarNight,
+}
+
+impl ReportedEvent {
+    /// Classify a missing `event_time` by comparing `noon_elevation`, the Sun's elevation angle
+    /// at local noon (its daily peak), against `elevation_angle_degrees`, the same (dip-adjusted)
+    /// elevation threshold `calc::SolarCalculations` uses to decide whether this particular event
+    /// occurs. If the Sun's peak for the day never reaches that threshold, it never did; otherwise
+    /// it must have stayed above it all day, since the event didn't occur.
+    fn new(
+        event_time: Option<DateTime<FixedOffset>>,
+        noon_elevation: f64,
+        elevation_angle_degrees: f64,
+    ) -> Self {
+        match event_time {
+            Some(datetime) => Self::Time(datetime),
+            None if noon_elevation >= elevation_angle_degrees => Self::PolarDay,
+            None => Self::PolarNight,
+        }
+    }
+}
+
+impl fmt::Display for ReportedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Time(datetime) => write!(f, "{datetime}"),
+            Self::PolarDay => write!(f, "Up all day"),
+            Self::PolarNight => write!(f, "Below horizon all day"),
+        }
+    }
+}
+
+impl Serialize for ReportedEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        match self {
+            Self::Time(datetime) => {
+                let mut state = serializer.serialize_struct("ReportedEvent", 2)?;
+                state.serialize_field("kind", "time")?;
+                state.serialize_field("value", &datetime.to_string())?;
+                state.end()
+            }
+            Self::PolarDay => {
+                let mut state = serializer.serialize_struct("ReportedEvent", 1)?;
+                state.serialize_field("kind", "polar_day")?;
+                state.end()
+            }
+            Self::PolarNight => {
+                let mut state = serializer.serialize_struct("ReportedEvent", 1)?;
+                state.serialize_field("kind", "polar_night")?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// A full set of sunrise, sunset and related event times for one calculated date.
+#[derive(Serialize)]
+pub struct Report {
+    date: String,
+    astronomical_dawn: ReportedEvent,
+    nautical_dawn: ReportedEvent,
+    civil_dawn: ReportedEvent,
+    sunrise: ReportedEvent,
+    solar_noon: ReportedEvent,
+    sunset: ReportedEvent,
+    civil_dusk: ReportedEvent,
+    nautical_dusk: ReportedEvent,
+    astronomical_dusk: ReportedEvent,
+}
+
+impl Report {
+    pub fn new(solar_calculations: SolarCalculations) -> Self {
+        // The report is always pinned to local noon, the Sun's daily peak elevation, so comparing
+        // that peak directly against each event's own (dip-adjusted) elevation threshold tells us
+        // whether a missing event is polar day or polar night.
+        let noon_elevation = solar_calculations.solar_elevation();
+
+        let reported_event = |name: EventName| -> ReportedEvent {
+            let event = Event::from_event_name(name, solar_calculations.elevation());
+            let elevation_angle_degrees = match &event {
+                Event::Fixed(fixed) => -*fixed.degrees_below_horizon,
+                // Solar noon always occurs, so this threshold is never actually consulted.
+                Event::Variable(_) => f64::NEG_INFINITY,
+            };
+
+            let event_time = solar_calculations.event_time(event).0;
+            ReportedEvent::new(event_time, noon_elevation, elevation_angle_degrees)
+        };
+
+        Self {
+            date: solar_calculations.date().format("%Y-%m-%d").to_string(),
+            astronomical_dawn: reported_event(EventName::AstronomicalDawn),
+            nautical_dawn: reported_event(EventName::NauticalDawn),
+            civil_dawn: reported_event(EventName::CivilDawn),
+            sunrise: reported_event(EventName::Sunrise),
+            solar_noon: reported_event(EventName::SolarNoon),
+            sunset: reported_event(EventName::Sunset),
+            civil_dusk: reported_event(EventName::CivilDusk),
+            nautical_dusk: reported_event(EventName::NauticalDusk),
+            astronomical_dusk: reported_event(EventName::AstronomicalDusk),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Date: {}", self.date)?;
+        writeln!(f, "Astronomical Dawn: {}", self.astronomical_dawn)?;
+        writeln!(f, "Nautical Dawn: {}", self.nautical_dawn)?;
+        writeln!(f, "Civil Dawn: {}", self.civil_dawn)?;
+        writeln!(f, "Sunrise: {}", self.sunrise)?;
+        writeln!(f, "Solar Noon: {}", self.solar_noon)?;
+        writeln!(f, "Sunset: {}", self.sunset)?;
+        writeln!(f, "Civil Dusk: {}", self.civil_dusk)?;
+        writeln!(f, "Nautical Dusk: {}", self.nautical_dusk)?;
+        write!(f, "Astronomical Dusk: {}", self.astronomical_dusk)
+    }
+}
+
+/// Real time data pertaining to the Sun's current position.
+pub struct PollReport {
+    date: String,
+    solar_elevation: f64,
+    day_part: DayPart,
+}
+
+impl PollReport {
+    pub fn new(solar_calculations: &SolarCalculations) -> Self {
+        let solar_elevation = solar_calculations.solar_elevation();
+
+        Self {
+            date: solar_calculations.date().to_rfc3339(),
+            solar_elevation,
+            day_part: DayPart::from_elevation_angle(solar_elevation),
+        }
+    }
+}
+
+impl fmt::Display for PollReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Date: {}", self.date)?;
+        writeln!(f, "Solar elevation: {:.4} degrees", self.solar_elevation)?;
+        write!(f, "Day part: {}", self.day_part)
+    }
+}
+
+impl Serialize for PollReport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("PollReport", 3)?;
+        state.serialize_field("date", &self.date)?;
+        state.serialize_field("solar_elevation", &self.solar_elevation)?;
+        state.serialize_field("day_part", &self.day_part.to_string())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_peak_just_above_the_events_own_threshold_is_polar_day() {
+        // +0.45 degrees would bucket as `DayPart::CivilTwilight`, not `DayPart::Day` (threshold
+        // +0.833), if compared through `DayPart::from_elevation_angle`, but sunrise/sunset's own
+        // threshold, which is what
+        // actually decided the missing event, is -0.833 degrees - well below that. The Sun is up
+        // continuously, so a missing sunrise here must be reported as polar day.
+        let result = ReportedEvent::new(None, 0.45, -0.833);
+        assert!(matches!(result, ReportedEvent::PolarDay));
+    }
+
+    #[test]
+    fn a_peak_below_the_events_own_threshold_is_polar_night() {
+        let result = ReportedEvent::new(None, -30.0, -0.833);
+        assert!(matches!(result, ReportedEvent::PolarNight));
+    }
+
+    #[test]
+    fn an_occurring_event_is_reported_as_its_time() {
+        let datetime =
+            DateTime::<FixedOffset>::parse_from_rfc3339("2026-06-21T12:00:00+00:00").unwrap();
+        let result = ReportedEvent::new(Some(datetime), 45.0, -0.833);
+        assert!(matches!(result, ReportedEvent::Time(dt) if dt == datetime));
+    }
+}
@@ -1,10 +1,10 @@
 use std::io::Write;
 use std::result;
 
-use chrono::Local;
+use chrono::{Duration, Local};
 use crossterm::{cursor, terminal, ExecutableCommand, QueueableCommand};
 
-use super::{calc, errors, report};
+use super::{calc, domain, errors, report, utils};
 
 type Result<T> = result::Result<T, errors::HeliocronError>;
 
@@ -64,3 +64,69 @@ pub fn poll(solar_calculations: calc::SolarCalculations, watch: bool, json: bool
 
     Ok(())
 }
+
+/// Sleep until `event` occurs (plus `offset`) on the configured date/location, then either exit
+/// or, if `run` is given, spawn that command.
+pub async fn wait(
+    solar_calculations: calc::SolarCalculations,
+    event: domain::EventName,
+    offset: Duration,
+    run: Option<String>,
+) -> Result<()> {
+    let event_time = solar_calculations
+        .event_time(domain::Event::from_event_name(
+            event,
+            solar_calculations.elevation(),
+        ))
+        .0
+        .ok_or_else(|| {
+            errors::HeliocronError::Calculation(
+                "the requested event does not occur on the configured date and location"
+                    .to_string(),
+            )
+        })?;
+
+    utils::wait(event_time + offset).await?;
+
+    if let Some(command) = run {
+        std::process::Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .spawn()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, TimeZone};
+
+    use super::*;
+    use crate::domain::{Coordinates, Elevation, Latitude, Longitude};
+
+    #[tokio::test]
+    async fn wait_bails_out_with_a_calculation_error_when_the_event_does_not_occur() {
+        // Svalbard in midsummer: the Sun never sets, so sunset has no event time to wait for.
+        let date = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2026, 6, 21, 12, 0, 0)
+            .unwrap();
+        let coordinates = Coordinates::new(
+            Latitude::new(78.2232).unwrap(),
+            Longitude::new(15.6267).unwrap(),
+        );
+        let solar_calculations =
+            calc::SolarCalculations::new(date, coordinates, Elevation::new(0.0), false);
+
+        let result = wait(
+            solar_calculations,
+            domain::EventName::Sunset,
+            Duration::zero(),
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(errors::HeliocronError::Calculation(_))));
+    }
+}
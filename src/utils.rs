@@ -1,3 +1,18 @@
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::errors::HeliocronError;
+
+/// Sleep until `wait_until`, returning immediately if that moment has already passed.
+pub async fn wait(wait_until: DateTime<FixedOffset>) -> Result<(), HeliocronError> {
+    let duration = (wait_until.with_timezone(&Utc) - Utc::now())
+        .to_std()
+        .unwrap_or(std::time::Duration::ZERO);
+
+    tokio::time::sleep(duration).await;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "integration-test")]
@@ -8,7 +23,10 @@ mod tests {
         use super::*;
 
         // Some time improbably far in the future.
-        let wait_until = FixedOffset::west(0).timestamp(9999999999, 0);
+        let wait_until = FixedOffset::west_opt(0)
+            .unwrap()
+            .timestamp_opt(9999999999, 0)
+            .unwrap();
         wait(wait_until).await.unwrap();
     }
 }